@@ -1,9 +1,74 @@
-use {super::*, crate::subcommand::wallet::transaction_builder::Target, crate::wallet::Wallet};
+use {
+  super::*,
+  crate::subcommand::wallet::transaction_builder::Target,
+  crate::wallet::Wallet,
+  bitcoin::{
+    blockdata::{opcodes, script},
+    consensus,
+    locktime::absolute::LockTime,
+    ScriptBuf, Sequence, TxIn, TxOut, Witness,
+  },
+  bitcoincore_rpc::json::GetTransactionResultDetailCategory,
+  dialoguer::{theme::ColorfulTheme, Confirm},
+};
+
+// Locks the inscribed and runic outputs that are not already locked, and
+// panics if `lock_unspent` reports success without locking one of them.
+impl Wallet {
+  pub(crate) fn lock_non_cardinal_outputs(
+    self,
+    client: &Client,
+    index: &Index,
+    inscriptions: &BTreeMap<SatPoint, InscriptionId>,
+    runic_outputs: &BTreeSet<OutPoint>,
+    unspent_outputs: &BTreeMap<OutPoint, Amount>,
+  ) -> Result {
+    let inscribed_outputs = inscriptions
+      .keys()
+      .map(|satpoint| satpoint.outpoint)
+      .collect::<BTreeSet<OutPoint>>();
+
+    let non_cardinal_outputs = unspent_outputs
+      .keys()
+      .filter(|utxo| inscribed_outputs.contains(utxo))
+      .chain(runic_outputs.iter())
+      .cloned()
+      .collect::<BTreeSet<OutPoint>>();
+
+    let already_locked = index.get_locked_outputs(self)?;
+
+    let newly_locked = non_cardinal_outputs
+      .difference(&already_locked)
+      .cloned()
+      .collect::<Vec<OutPoint>>();
+
+    if newly_locked.is_empty() {
+      return Ok(());
+    }
+
+    if !client.lock_unspent(&newly_locked)? {
+      bail!("failed to lock UTXOs");
+    }
+
+    let locked_outputs = index.get_locked_outputs(self)?;
+
+    for outpoint in &newly_locked {
+      assert!(
+        locked_outputs.contains(outpoint),
+        "outpoint {outpoint} was not locked by lock_unspent"
+      );
+    }
+
+    Ok(())
+  }
+}
 
 #[derive(Debug, Parser, Clone)]
 pub(crate) struct Send {
-  address: Address<NetworkUnchecked>,
-  outgoing: Outgoing,
+  #[arg(required_unless_present_any = ["to", "split", "burn", "replace"])]
+  address: Option<Address<NetworkUnchecked>>,
+  #[arg(required_unless_present_any = ["sweep", "to", "split", "burn", "replace"])]
+  outgoing: Option<Outgoing>,
   #[arg(
     long,
     help = "Consider spending outpoint <UTXO>, even if it is unconfirmed or contains inscriptions"
@@ -23,6 +88,82 @@ pub(crate) struct Send {
   pub(crate) postage: Option<Amount>,
   #[clap(long, help = "Require this utxo to be spent. Useful for forcing CPFP.")]
   pub(crate) force_input: Vec<OutPoint>,
+  #[clap(
+    long,
+    conflicts_with = "outgoing",
+    help = "Sweep entire spendable cardinal balance to <ADDRESS>, subtracting fee from the swept amount."
+  )]
+  pub(crate) sweep: bool,
+  #[clap(
+    long = "to",
+    value_name = "ADDRESS:AMOUNT",
+    conflicts_with_all = ["address", "outgoing", "sweep", "split", "per_output"],
+    help = "Send <AMOUNT> to <ADDRESS>. May be repeated to pay multiple recipients in a single transaction."
+  )]
+  pub(crate) to: Vec<AddressAmount>,
+  #[clap(
+    long,
+    requires = "per_output",
+    conflicts_with_all = ["address", "outgoing", "sweep", "to"],
+    help = "Split spendable cardinals into <SPLIT> fresh outputs of --per-output each."
+  )]
+  pub(crate) split: Option<u32>,
+  #[clap(
+    long,
+    requires = "split",
+    help = "Amount of each output created by --split."
+  )]
+  pub(crate) per_output: Option<Amount>,
+  #[clap(
+    long,
+    value_name = "OUTGOING",
+    conflicts_with_all = ["address", "outgoing", "sweep", "to", "split"],
+    help = "Permanently destroy the inscription or satpoint given as <OUTGOING> by sending it to a zero-value OP_RETURN output."
+  )]
+  pub(crate) burn: Option<Outgoing>,
+  #[clap(
+    long,
+    help = "Don't ask for confirmation before --burn destroys an inscription."
+  )]
+  pub(crate) yes: bool,
+  #[clap(
+    long,
+    help = "Allow --burn to destroy an output that contains an unrelated rare sat."
+  )]
+  pub(crate) force_rare_sat_burn: bool,
+  #[clap(
+    long,
+    help = "Opt in to BIP-125 replace-by-fee, allowing this transaction to be fee-bumped with --replace."
+  )]
+  pub(crate) replaceable: bool,
+  #[clap(
+    long,
+    value_name = "TXID",
+    conflicts_with_all = ["address", "outgoing", "sweep", "to", "split", "burn"],
+    help = "Replace the unconfirmed, replaceable transaction <TXID> with the same inputs and outputs at a higher --fee-rate."
+  )]
+  pub(crate) replace: Option<Txid>,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct AddressAmount {
+  address: Address<NetworkUnchecked>,
+  amount: Amount,
+}
+
+impl FromStr for AddressAmount {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let (address, amount) = s
+      .split_once(':')
+      .ok_or_else(|| anyhow!("expected ADDRESS:AMOUNT, got `{s}`"))?;
+
+    Ok(Self {
+      address: address.parse()?,
+      amount: amount.parse()?,
+    })
+  }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -32,11 +173,6 @@ pub struct Output {
 
 impl Send {
   pub(crate) fn run(self, options: Options) -> SubcommandResult {
-    let address = self
-      .address
-      .clone()
-      .require_network(options.chain().network())?;
-
     let index = Index::open(&options)?;
     index.update()?;
 
@@ -44,6 +180,16 @@ impl Send {
 
     let client = options.bitcoin_rpc_client_for_wallet_command(false)?;
 
+    if let Some(txid) = self.replace {
+      if self.coin_control || !self.utxo.is_empty() {
+        bail!("--coin_control and --utxo don't work with --replace");
+      }
+
+      let txid = Self::send_replace(&client, txid, self.fee_rate)?;
+
+      return Ok(Box::new(Output { transaction: txid }));
+    }
+
     let mut unspent_outputs = if self.coin_control {
       BTreeMap::new()
     } else if options.ignore_outdated_index {
@@ -74,7 +220,155 @@ impl Send {
     let runic_outputs =
       index.get_runic_outputs(&unspent_outputs.keys().cloned().collect::<Vec<OutPoint>>())?;
 
-    let satpoint = match self.outgoing {
+    if !self.to.is_empty() || self.split.is_some() {
+      if self.coin_control || !self.utxo.is_empty() {
+        bail!("--coin_control and --utxo don't work when sending to multiple recipients");
+      }
+
+      wallet.lock_non_cardinal_outputs(&client, &index, &inscriptions, &runic_outputs, &unspent_outputs)?;
+
+      let outputs = if let Some(count) = self.split {
+        let per_output = self
+          .per_output
+          .ok_or_else(|| anyhow!("--split requires --per-output"))?;
+
+        let mut outputs = BTreeMap::new();
+        for _ in 0..count {
+          let address = get_change_address(&client, chain)?;
+
+          ensure!(
+            per_output >= address.script_pubkey().dust_value(),
+            "per-output amount {per_output} is below the dust threshold"
+          );
+
+          ensure!(
+            outputs.insert(address.clone(), per_output).is_none(),
+            "wallet returned change address {address} more than once while splitting"
+          );
+        }
+        outputs
+      } else {
+        let mut outputs = BTreeMap::new();
+        for to in &self.to {
+          let address = to.address.clone().require_network(chain.network())?;
+          ensure!(
+            to.amount >= address.script_pubkey().dust_value(),
+            "amount {} for {address} is below the dust threshold",
+            to.amount
+          );
+          ensure!(
+            outputs.insert(address.clone(), to.amount).is_none(),
+            "duplicate recipient address {address} in --to"
+          );
+        }
+        outputs
+      };
+
+      let txid = Self::send_many(&client, outputs, self.fee_rate.n(), self.replaceable)?;
+
+      return Ok(Box::new(Output { transaction: txid }));
+    }
+
+    if let Some(outgoing) = self.burn.clone() {
+      if self.coin_control || !self.utxo.is_empty() {
+        bail!("--coin_control and --utxo don't work when burning");
+      }
+
+      let satpoint = match outgoing {
+        Outgoing::SatPoint(satpoint) => {
+          for inscription_satpoint in inscriptions.keys() {
+            if satpoint == *inscription_satpoint {
+              bail!("inscriptions must be burned by inscription ID");
+            }
+          }
+
+          ensure!(
+            !runic_outputs.contains(&satpoint.outpoint),
+            "runic outpoints may not be burned by satpoint"
+          );
+
+          satpoint
+        }
+        Outgoing::InscriptionId(id) => index
+          .get_inscription_satpoint_by_id(id)?
+          .ok_or_else(|| anyhow!("Inscription {id} not found"))?,
+        Outgoing::Amount(_) => bail!("--burn requires an inscription ID or satpoint, not an amount"),
+      };
+
+      if !self.force_rare_sat_burn {
+        if let Some(crate::index::List::Unspent(ranges)) = index.list(satpoint.outpoint)? {
+          if let Some(rare_sat) = ranges
+            .iter()
+            .find_map(|(start, end)| Self::rare_sat_in_range(*start, *end))
+          {
+            bail!(
+              "refusing to burn {satpoint}, which contains the rare sat {rare_sat}; pass --force-rare-sat-burn to override"
+            );
+          }
+        }
+      }
+
+      if !self.yes
+        && !Confirm::with_theme(&ColorfulTheme::default())
+          .with_prompt(format!(
+            "This transaction will burn {satpoint}, permanently destroying it. Continue?"
+          ))
+          .default(false)
+          .interact()?
+      {
+        bail!("burn aborted");
+      }
+
+      wallet.lock_non_cardinal_outputs(&client, &index, &inscriptions, &runic_outputs, &unspent_outputs)?;
+
+      let change_address = get_change_address(&client, chain)?;
+
+      let txid = Self::send_burn(
+        &client,
+        satpoint,
+        self.force_input,
+        self.fee_rate,
+        self.replaceable,
+        change_address,
+      )?;
+
+      return Ok(Box::new(Output { transaction: txid }));
+    }
+
+    let address = self
+      .address
+      .clone()
+      .expect("clap enforces address unless --to, --split, or --burn")
+      .require_network(chain.network())?;
+
+    if self.sweep {
+      if self.coin_control || !self.utxo.is_empty() {
+        bail!("--coin_control and --utxo don't work when sweeping cardinals");
+      }
+
+      let inscribed_outputs = inscriptions
+        .keys()
+        .map(|satpoint| satpoint.outpoint)
+        .collect::<HashSet<OutPoint>>();
+
+      let spendable = unspent_outputs
+        .iter()
+        .filter(|(outpoint, _)| {
+          !inscribed_outputs.contains(outpoint)
+            && !runic_outputs.contains(outpoint)
+            && !locked_outputs.contains(outpoint)
+        })
+        .map(|(outpoint, amount)| (*outpoint, *amount))
+        .collect::<BTreeMap<OutPoint, Amount>>();
+
+      wallet.lock_non_cardinal_outputs(&client, &index, &inscriptions, &runic_outputs, &unspent_outputs)?;
+
+      let txid = Self::send_sweep(&client, address, self.fee_rate, spendable, self.replaceable)?;
+
+      return Ok(Box::new(Output { transaction: txid }));
+    }
+
+    let satpoint = match self.outgoing.expect("clap enforces outgoing unless --sweep, --to, --split, or --burn") {
       Outgoing::SatPoint(satpoint) => {
         for inscription_satpoint in inscriptions.keys() {
           if satpoint == *inscription_satpoint {
@@ -96,8 +390,8 @@ impl Send {
         if self.coin_control || !self.utxo.is_empty() {
           bail!("--coin_control and --utxo don't work when sending cardinals");
         }
-        Self::lock_inscriptions(&client, inscriptions, runic_outputs, unspent_outputs)?;
-        let txid = Self::send_amount(&client, amount, address, self.fee_rate.n())?;
+        wallet.lock_non_cardinal_outputs(&client, &index, &inscriptions, &runic_outputs, &unspent_outputs)?;
+        let txid = Self::send_amount(&client, amount, address, self.fee_rate.n(), self.replaceable)?;
         return Ok(Box::new(Output { transaction: txid }));
       }
     };
@@ -113,7 +407,7 @@ impl Send {
       Target::Postage
     };
 
-    let unsigned_transaction = TransactionBuilder::new(
+    let mut unsigned_transaction = TransactionBuilder::new(
       satpoint,
       inscriptions,
       unspent_outputs,
@@ -127,6 +421,14 @@ impl Send {
     )
     .build_transaction()?;
 
+    // TransactionBuilder itself always builds a non-replaceable transaction;
+    // opt in to BIP-125 here rather than threading the flag through it.
+    if self.replaceable {
+      for input in &mut unsigned_transaction.input {
+        input.sequence = Sequence::ENABLE_RBF_NO_LOCKTIME;
+      }
+    }
+
     let signed_tx = client
       .sign_raw_transaction_with_wallet(&unsigned_transaction, None, None)?
       .hex;
@@ -136,32 +438,206 @@ impl Send {
     Ok(Box::new(Output { transaction: txid }))
   }
 
-  fn lock_inscriptions(
+  // a sat's rarity is determined entirely by whether it is the first sat of
+  // its block, so the only rare-or-better sats in `[start, end)` are the
+  // block boundaries that range crosses, which may be more than the range's
+  // own endpoints once ord has coalesced ranges across multiple blocks
+  fn rare_sat_in_range(start: u64, end: u64) -> Option<Sat> {
+    let mut height = Sat(start).height();
+
+    loop {
+      let boundary = height.starting_sat();
+
+      if boundary.n() >= end {
+        return None;
+      }
+
+      if boundary.n() >= start && boundary.rarity() > Rarity::Common {
+        return Some(boundary);
+      }
+
+      height = Height(height.n() + 1);
+    }
+  }
+
+  fn send_sweep(
     client: &Client,
-    inscriptions: BTreeMap<SatPoint, InscriptionId>,
-    runic_outputs: BTreeSet<OutPoint>,
-    unspent_outputs: BTreeMap<OutPoint, bitcoin::Amount>,
-  ) -> Result {
-    let all_inscription_outputs = inscriptions
-      .keys()
-      .map(|satpoint| satpoint.outpoint)
-      .collect::<HashSet<OutPoint>>();
+    address: Address,
+    fee_rate: FeeRate,
+    unspent_outputs: BTreeMap<OutPoint, Amount>,
+    replaceable: bool,
+  ) -> Result<Txid> {
+    ensure!(
+      !unspent_outputs.is_empty(),
+      "wallet contains no spendable cardinal UTXOs to sweep"
+    );
+
+    let total_input = unspent_outputs.values().copied().sum::<Amount>();
 
-    let locked_outputs = unspent_outputs
+    let sequence = if replaceable {
+      Sequence::ENABLE_RBF_NO_LOCKTIME
+    } else {
+      Sequence::MAX
+    };
+
+    let input = unspent_outputs
       .keys()
-      .filter(|utxo| all_inscription_outputs.contains(utxo))
-      .chain(runic_outputs.iter())
-      .cloned()
-      .collect::<Vec<OutPoint>>();
+      .map(|outpoint| TxIn {
+        previous_output: *outpoint,
+        script_sig: ScriptBuf::new(),
+        sequence,
+        witness: Witness::new(),
+      })
+      .collect::<Vec<TxIn>>();
 
-    if !client.lock_unspent(&locked_outputs)? {
-      bail!("failed to lock UTXOs");
+    let build = |value: Amount| Transaction {
+      version: 2,
+      lock_time: LockTime::ZERO,
+      input: input.clone(),
+      output: vec![TxOut {
+        value: value.to_sat(),
+        script_pubkey: address.script_pubkey(),
+      }],
+    };
+
+    // build once with the full input value so we can learn the signed vsize, then
+    // recompute the output value from the actual fee and sign again
+    let estimate = client.sign_raw_transaction_with_wallet(&build(total_input), None, None)?;
+
+    let fee = fee_rate.fee(consensus::deserialize::<Transaction>(&estimate.hex)?.vsize());
+
+    ensure!(
+      total_input > fee,
+      "total input of {total_input} is not enough to pay a fee of {fee} when sweeping"
+    );
+
+    let value = total_input - fee;
+
+    ensure!(
+      value >= address.script_pubkey().dust_value(),
+      "sweep output value of {value} is below the dust threshold"
+    );
+
+    let signed_tx = client.sign_raw_transaction_with_wallet(&build(value), None, None)?.hex;
+
+    Ok(client.send_raw_transaction(&signed_tx)?)
+  }
+
+  fn send_burn(
+    client: &Client,
+    satpoint: SatPoint,
+    force_input: Vec<OutPoint>,
+    fee_rate: FeeRate,
+    replaceable: bool,
+    change_address: Address,
+  ) -> Result<Txid> {
+    let sequence = if replaceable {
+      Sequence::ENABLE_RBF_NO_LOCKTIME
+    } else {
+      Sequence::MAX
+    };
+
+    let mut input = vec![TxIn {
+      previous_output: satpoint.outpoint,
+      script_sig: ScriptBuf::new(),
+      sequence,
+      witness: Witness::new(),
+    }];
+
+    input.extend(force_input.into_iter().map(|previous_output| TxIn {
+      previous_output,
+      script_sig: ScriptBuf::new(),
+      sequence,
+      witness: Witness::new(),
+    }));
+
+    let mut total_input = Amount::ZERO;
+    for tx_in in &input {
+      let previous_output = tx_in.previous_output;
+      total_input += Amount::from_sat(
+        client.get_raw_transaction(&previous_output.txid, None)?.output[previous_output.vout as usize].value,
+      );
     }
 
-    Ok(())
+    let burn_output = TxOut {
+      value: 0,
+      script_pubkey: script::Builder::new()
+        .push_opcode(opcodes::all::OP_RETURN)
+        .into_script(),
+    };
+
+    let build = |change: Amount| Transaction {
+      version: 2,
+      lock_time: LockTime::ZERO,
+      input: input.clone(),
+      output: vec![
+        burn_output.clone(),
+        TxOut {
+          value: change.to_sat(),
+          script_pubkey: change_address.script_pubkey(),
+        },
+      ],
+    };
+
+    // build once with the full input value so we can learn the signed vsize, then
+    // recompute the change output from the actual fee and sign again
+    let estimate = client.sign_raw_transaction_with_wallet(&build(total_input), None, None)?;
+
+    let fee = fee_rate.fee(consensus::deserialize::<Transaction>(&estimate.hex)?.vsize());
+
+    ensure!(
+      total_input > fee,
+      "total input of {total_input} is not enough to pay a fee of {fee} when burning"
+    );
+
+    let change = total_input - fee;
+
+    ensure!(
+      change >= change_address.script_pubkey().dust_value(),
+      "change of {change} left after burning is below the dust threshold; lower --fee-rate or burn a larger postage"
+    );
+
+    let signed_tx = client.sign_raw_transaction_with_wallet(&build(change), None, None)?.hex;
+
+    Ok(client.send_raw_transaction(&signed_tx)?)
+  }
+
+  fn send_many(
+    client: &Client,
+    outputs: BTreeMap<Address, Amount>,
+    fee_rate: f64,
+    replaceable: bool,
+  ) -> Result<Txid> {
+    ensure!(!outputs.is_empty(), "no recipients given");
+
+    let mut amounts = serde_json::Map::new();
+    for (address, amount) in &outputs {
+      amounts.insert(address.to_string(), amount.to_btc().into());
+    }
+
+    Ok(client.call(
+      "sendmany",
+      &[
+        "".into(),                           //  1. dummy, must be ""
+        serde_json::Value::Object(amounts),  //  2. amounts
+        serde_json::Value::Null,             //  3. minconf
+        serde_json::Value::Null,             //  4. comment
+        serde_json::Value::Null,             //  5. subtractfeefrom
+        replaceable.into(),                  //  6. replaceable
+        serde_json::Value::Null,             //  7. conf_target
+        serde_json::Value::Null,             //  8. estimate_mode
+        fee_rate.into(),                     //  9. fee_rate
+      ],
+    )?)
   }
 
-  fn send_amount(client: &Client, amount: Amount, address: Address, fee_rate: f64) -> Result<Txid> {
+  fn send_amount(
+    client: &Client,
+    amount: Amount,
+    address: Address,
+    fee_rate: f64,
+    replaceable: bool,
+  ) -> Result<Txid> {
     Ok(client.call(
       "sendtoaddress",
       &[
@@ -170,7 +646,7 @@ impl Send {
         serde_json::Value::Null,    //  3. comment
         serde_json::Value::Null,    //  4. comment_to
         serde_json::Value::Null,    //  5. subtractfeefromamount
-        serde_json::Value::Null,    //  6. replaceable
+        replaceable.into(),         //  6. replaceable
         serde_json::Value::Null,    //  7. conf_target
         serde_json::Value::Null,    //  8. estimate_mode
         serde_json::Value::Null,    //  9. avoid_reuse
@@ -178,4 +654,284 @@ impl Send {
       ],
     )?)
   }
+
+  fn send_replace(client: &Client, txid: Txid, fee_rate: FeeRate) -> Result<Txid> {
+    let previous = client.get_raw_transaction(&txid, None)?;
+
+    ensure!(
+      previous.input.iter().any(|input| input.sequence.is_rbf()),
+      "transaction {txid} did not opt in to replace-by-fee and cannot be replaced"
+    );
+
+    let mut total_input = Amount::ZERO;
+    for input in &previous.input {
+      let previous_output = input.previous_output;
+      total_input += Amount::from_sat(
+        client.get_raw_transaction(&previous_output.txid, None)?.output[previous_output.vout as usize].value,
+      );
+    }
+
+    let total_output = previous
+      .output
+      .iter()
+      .map(|output| Amount::from_sat(output.value))
+      .sum::<Amount>();
+
+    let old_fee = total_input
+      .checked_sub(total_output)
+      .ok_or_else(|| anyhow!("transaction {txid} pays a negative fee"))?;
+
+    let build = |output: Vec<TxOut>| Transaction {
+      version: 2,
+      lock_time: LockTime::ZERO,
+      input: previous
+        .input
+        .iter()
+        .map(|input| TxIn {
+          previous_output: input.previous_output,
+          script_sig: ScriptBuf::new(),
+          sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+          witness: Witness::new(),
+        })
+        .collect(),
+      output,
+    };
+
+    // build once with the old outputs so we can learn the signed vsize at the new fee rate
+    let estimate = client.sign_raw_transaction_with_wallet(&build(previous.output.clone()), None, None)?;
+
+    let fee = fee_rate.fee(consensus::deserialize::<Transaction>(&estimate.hex)?.vsize());
+
+    ensure!(
+      fee > old_fee,
+      "--fee-rate must bump the fee above the {old_fee} already paid by {txid}"
+    );
+
+    let increase = fee - old_fee;
+
+    let paid_vouts = client
+      .get_transaction(&txid, None)?
+      .details
+      .into_iter()
+      .filter(|detail| detail.category == GetTransactionResultDetailCategory::Send)
+      .map(|detail| detail.vout)
+      .collect::<BTreeSet<u32>>();
+
+    let change_vouts = (0..previous.output.len() as u32)
+      .filter(|vout| !paid_vouts.contains(vout))
+      .collect::<Vec<u32>>();
+
+    let change_vout = match change_vouts.as_slice() {
+      [change_vout] => *change_vout as usize,
+      [] => bail!("could not identify a change output to absorb the fee increase in {txid}"),
+      _ => bail!("transaction {txid} has more than one candidate change output"),
+    };
+
+    let mut output = previous.output;
+    let change = &mut output[change_vout];
+    let change_value = Amount::from_sat(change.value);
+
+    ensure!(
+      change_value > increase,
+      "change output of {txid} cannot absorb the {increase} fee increase"
+    );
+
+    let new_change_value = change_value - increase;
+
+    ensure!(
+      new_change_value >= change.script_pubkey.dust_value(),
+      "change of {new_change_value} left after bumping the fee is below the dust threshold"
+    );
+
+    change.value = new_change_value.to_sat();
+
+    let signed_tx = client.sign_raw_transaction_with_wallet(&build(output), None, None)?.hex;
+
+    Ok(client.send_raw_transaction(&signed_tx)?)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn burn_does_not_require_an_address() {
+    Send::try_parse_from([
+      "send",
+      "--fee-rate",
+      "1",
+      "--burn",
+      "6ac5f5bd0bca1237756f0c1f6ac28f43f28fd61f7a8b0a0a5b6e4a3e4a6e4a1ai0",
+    ])
+    .unwrap();
+  }
+
+  #[test]
+  fn burn_by_satpoint_does_not_require_an_address() {
+    Send::try_parse_from([
+      "send",
+      "--fee-rate",
+      "1",
+      "--burn",
+      "6ac5f5bd0bca1237756f0c1f6ac28f43f28fd61f7a8b0a0a5b6e4a3e4a6e4a1a:0:0",
+    ])
+    .unwrap();
+  }
+
+  #[test]
+  fn burn_conflicts_with_address() {
+    assert!(Send::try_parse_from([
+      "send",
+      "--fee-rate",
+      "1",
+      "--burn",
+      "6ac5f5bd0bca1237756f0c1f6ac28f43f28fd61f7a8b0a0a5b6e4a3e4a6e4a1ai0",
+      "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+    ])
+    .is_err());
+  }
+
+  #[test]
+  fn address_amount_parses_address_and_amount() {
+    let AddressAmount { address, amount } = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4:1btc"
+      .parse()
+      .unwrap();
+
+    assert_eq!(
+      address,
+      "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4"
+        .parse::<Address<NetworkUnchecked>>()
+        .unwrap()
+    );
+    assert_eq!(amount, "1btc".parse::<Amount>().unwrap());
+  }
+
+  #[test]
+  fn address_amount_requires_a_colon() {
+    assert!("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t41btc"
+      .parse::<AddressAmount>()
+      .is_err());
+  }
+
+  #[test]
+  fn address_amount_rejects_invalid_address() {
+    assert!("not an address:1btc".parse::<AddressAmount>().is_err());
+  }
+
+  #[test]
+  fn address_amount_rejects_invalid_amount() {
+    assert!(
+      "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4:not an amount"
+        .parse::<AddressAmount>()
+        .is_err()
+    );
+  }
+
+  #[test]
+  fn sweep_does_not_require_outgoing() {
+    Send::try_parse_from([
+      "send",
+      "--fee-rate",
+      "1",
+      "--sweep",
+      "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+    ])
+    .unwrap();
+  }
+
+  #[test]
+  fn sweep_conflicts_with_outgoing() {
+    assert!(Send::try_parse_from([
+      "send",
+      "--fee-rate",
+      "1",
+      "--sweep",
+      "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+      "6ac5f5bd0bca1237756f0c1f6ac28f43f28fd61f7a8b0a0a5b6e4a3e4a6e4a1ai0",
+    ])
+    .is_err());
+  }
+
+  #[test]
+  fn to_does_not_require_an_address_or_outgoing() {
+    Send::try_parse_from([
+      "send",
+      "--fee-rate",
+      "1",
+      "--to",
+      "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4:1btc",
+    ])
+    .unwrap();
+  }
+
+  #[test]
+  fn to_conflicts_with_address() {
+    assert!(Send::try_parse_from([
+      "send",
+      "--fee-rate",
+      "1",
+      "--to",
+      "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4:1btc",
+      "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+    ])
+    .is_err());
+  }
+
+  #[test]
+  fn split_does_not_require_an_address_or_outgoing() {
+    Send::try_parse_from([
+      "send",
+      "--fee-rate",
+      "1",
+      "--split",
+      "2",
+      "--per-output",
+      "1btc",
+    ])
+    .unwrap();
+  }
+
+  #[test]
+  fn split_requires_per_output() {
+    assert!(Send::try_parse_from(["send", "--fee-rate", "1", "--split", "2"]).is_err());
+  }
+
+  #[test]
+  fn replace_does_not_require_an_address() {
+    Send::try_parse_from([
+      "send",
+      "--fee-rate",
+      "1",
+      "--replace",
+      "6ac5f5bd0bca1237756f0c1f6ac28f43f28fd61f7a8b0a0a5b6e4a3e4a6e4a1a",
+    ])
+    .unwrap();
+  }
+
+  #[test]
+  fn replace_conflicts_with_address() {
+    assert!(Send::try_parse_from([
+      "send",
+      "--fee-rate",
+      "1",
+      "--replace",
+      "6ac5f5bd0bca1237756f0c1f6ac28f43f28fd61f7a8b0a0a5b6e4a3e4a6e4a1a",
+      "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+    ])
+    .is_err());
+  }
+
+  #[test]
+  fn replaceable_works_alongside_address_and_outgoing() {
+    Send::try_parse_from([
+      "send",
+      "--fee-rate",
+      "1",
+      "--replaceable",
+      "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4",
+      "6ac5f5bd0bca1237756f0c1f6ac28f43f28fd61f7a8b0a0a5b6e4a3e4a6e4a1ai0",
+    ])
+    .unwrap();
+  }
 }